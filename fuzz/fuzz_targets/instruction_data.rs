@@ -0,0 +1,24 @@
+#![no_main]
+
+use core::mem::size_of;
+
+use libfuzzer_sys::fuzz_target;
+use melt::{instructions::*, state::Cranker};
+
+// Parsers must reject malformed input with an error, never panic.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(deposit) = DepositInstructionData::try_from(data) {
+        assert_eq!(deposit.amount.to_le_bytes(), data);
+    }
+
+    if let Ok(set_crankers) = SetCrankersInstructionData::try_from(data) {
+        assert_eq!(
+            set_crankers.crankers.len() * size_of::<Cranker>(),
+            data.len()
+        );
+    }
+
+    if let Ok(record_spend) = RecordSpendInstructionData::try_from(data) {
+        assert_eq!(record_spend.lamports.to_le_bytes(), data);
+    }
+});
@@ -0,0 +1,156 @@
+#![no_main]
+
+use libfuzzer_sys::{arbitrary::Arbitrary, fuzz_target};
+use melt::{pda::VAULT_SEED, state::Config};
+use mollusk_svm::{program, Mollusk};
+use solana_account::Account;
+use solana_instruction::{error::InstructionError, AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+/// Accounts are drawn from a small pool of keys so inputs can alias the
+/// same account across roles, and so the real system program and a few
+/// valid PDAs show up often enough to get past the first checks.
+const KEYS: usize = 8;
+
+/// Keeps arbitrary balances far from `u64::MAX`, so the runtime's own
+/// lamport sums cannot overflow.
+const MAX_LAMPORTS: u64 = 1 << 50;
+
+#[derive(Arbitrary, Debug)]
+enum Owner {
+    System,
+    Program,
+    Other,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzAccount {
+    key: u8,
+    is_signer: bool,
+    is_writable: bool,
+    executable: bool,
+    lamports: u64,
+    owner: Owner,
+    data: Vec<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzInput {
+    instruction_data: Vec<u8>,
+    accounts: Vec<FuzzAccount>,
+}
+
+struct Harness {
+    mollusk: Mollusk,
+    program_id: Pubkey,
+    keys: [Pubkey; KEYS],
+}
+
+impl Harness {
+    fn new() -> Self {
+        let program_id = Pubkey::new_unique();
+        let mollusk = Mollusk::new(&program_id, "melt");
+
+        let authority = Pubkey::new_unique();
+        let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &program_id).0;
+
+        let keys = [
+            program::keyed_account_for_system_program().0,
+            authority,
+            pda(&[VAULT_SEED, authority.as_ref()]),
+            pda(&[Config::SEED, authority.as_ref()]),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+
+        Self {
+            mollusk,
+            program_id,
+            keys,
+        }
+    }
+
+    fn run(&self, input: FuzzInput) {
+        let system_program = program::keyed_account_for_system_program();
+
+        let mut metas = Vec::with_capacity(input.accounts.len());
+        let mut accounts: Vec<(Pubkey, Account)> = Vec::with_capacity(input.accounts.len());
+
+        for fuzz in input.accounts {
+            let key = self.keys[fuzz.key as usize % KEYS];
+            metas.push(AccountMeta {
+                pubkey: key,
+                is_signer: fuzz.is_signer,
+                is_writable: fuzz.is_writable,
+            });
+
+            // The first occurrence of a key defines its account state.
+            if accounts.iter().any(|(k, _)| k.eq(&key)) {
+                continue;
+            }
+
+            let account = if key.eq(&system_program.0) {
+                system_program.1.clone()
+            } else {
+                let owner = match fuzz.owner {
+                    Owner::System => system_program.0,
+                    Owner::Program => self.program_id,
+                    Owner::Other => Pubkey::new_unique(),
+                };
+
+                Account {
+                    lamports: fuzz.lamports % MAX_LAMPORTS,
+                    data: fuzz.data,
+                    owner,
+                    executable: fuzz.executable,
+                    rent_epoch: 0,
+                }
+            };
+            accounts.push((key, account));
+        }
+
+        let instruction =
+            Instruction::new_with_bytes(self.program_id, &input.instruction_data, metas);
+
+        // Mollusk reports program failures in the result rather than by
+        // panicking, so any error is fine unless it is a crash. Running out
+        // of compute also aborts the program, and is not one.
+        let result = self.mollusk.process_instruction(&instruction, &accounts);
+        if let Err(err) = &result.raw_result {
+            let exhausted =
+                result.compute_units_consumed >= self.mollusk.compute_budget.compute_unit_limit;
+            if is_crash(err) && !exhausted {
+                panic!("program crashed: {err:?}");
+            }
+        }
+    }
+}
+
+/// Errors the program itself can never return: a panic, abort or VM fault
+/// inside the program, or an account write the runtime had to reject.
+fn is_crash(err: &InstructionError) -> bool {
+    matches!(
+        err,
+        InstructionError::ProgramFailedToComplete
+            | InstructionError::ProgramFailedToCompile
+            | InstructionError::UnbalancedInstruction
+            | InstructionError::ExternalAccountLamportSpend
+            | InstructionError::ExternalAccountDataModified
+            | InstructionError::ReadonlyLamportChange
+            | InstructionError::ReadonlyDataModified
+            | InstructionError::ExecutableModified
+            | InstructionError::ExecutableLamportChange
+            | InstructionError::ExecutableDataModified
+            | InstructionError::ModifiedProgramId
+    )
+}
+
+thread_local! {
+    static HARNESS: Harness = Harness::new();
+}
+
+fuzz_target!(|input: FuzzInput| {
+    HARNESS.with(|harness| harness.run(input));
+});
@@ -0,0 +1,86 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+
+/// Account must have signed the transaction.
+pub fn signer(account: &AccountView) -> ProgramResult {
+    if !account.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+/// Account must be passed as writable.
+pub fn writable(account: &AccountView) -> ProgramResult {
+    if !account.is_writable() {
+        return Err(ProgramError::Immutable);
+    }
+    Ok(())
+}
+
+/// Account must be owned by `owner`.
+pub fn owned_by(account: &AccountView, owner: &Address) -> ProgramResult {
+    if !account.owned_by(owner) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    Ok(())
+}
+
+/// Account must not be an executable (program) account.
+pub fn not_executable(account: &AccountView) -> ProgramResult {
+    if account.executable() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
+
+/// Account must be the expected program.
+pub fn program(account: &AccountView, program_id: &Address) -> ProgramResult {
+    if account.address().ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(())
+}
+
+/// Account must live at `expected`, typically a derived PDA.
+pub fn address(account: &AccountView, expected: &Address) -> ProgramResult {
+    if account.address().ne(expected) {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    Ok(())
+}
+
+/// No account may appear more than once in `accounts`.
+///
+/// Passing the same account under two roles lets an attacker alias e.g. the
+/// payer and the destination, so every handler rejects it up front.
+pub fn unique(accounts: &[&AccountView]) -> ProgramResult {
+    for (i, a) in accounts.iter().enumerate() {
+        if accounts[i + 1..]
+            .iter()
+            .any(|b| a.address().eq(b.address()))
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+    Ok(())
+}
+
+/// Like [`unique`], for handlers that also take a variable list of
+/// `remaining` accounts: those may repeat neither each other nor any of
+/// `accounts`.
+pub fn unique_with_remaining(
+    accounts: &[&AccountView],
+    remaining: &[AccountView],
+) -> ProgramResult {
+    unique(accounts)?;
+
+    for (i, a) in remaining.iter().enumerate() {
+        if accounts.iter().any(|b| a.address().eq(b.address()))
+            || remaining[i + 1..]
+                .iter()
+                .any(|b| a.address().eq(b.address()))
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+    Ok(())
+}
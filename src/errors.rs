@@ -0,0 +1,28 @@
+use pinocchio::error::ProgramError;
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MeltError {
+    /// Account layout is newer than this program, or was never valid.
+    UnsupportedAccountVersion = 0,
+    /// Account layout is older than this program; call `Migrate` first.
+    AccountNeedsMigration,
+    /// Account data does not start with a known discriminator.
+    UnknownAccountType,
+    /// Signer is not the authority recorded on the account.
+    InvalidAuthority,
+    /// Signer is not on the config's cranker allow-list.
+    UnauthorizedCranker,
+    /// Spend would push the cranker past its epoch budget cap.
+    BudgetExceeded,
+    /// `Tend` was called without any accounts to tidy up.
+    NothingToTend,
+    /// Account is still within its retention window.
+    AccountNotExpired,
+}
+
+impl From<MeltError> for ProgramError {
+    fn from(e: MeltError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
@@ -1,35 +1,105 @@
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{checks, pda};
+
 pub struct DepositAccounts<'a> {
-    pub owner: &'a AccountInfo,
-    pub vault: &'a AccountInfo,
+    pub owner: &'a AccountView,
+    pub vault: &'a AccountView,
 }
 
-impl<'a> TryFrom<&'a [AccountInfo]> for DepositAccounts<'a> {
+impl<'a> TryFrom<(&'a Address, &'a [AccountView])> for DepositAccounts<'a> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [owner, vault, _] = accounts else {
+    fn try_from(
+        (program_id, accounts): (&'a Address, &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let [owner, vault, system_program, ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         // Accounts Checks
-        if !owner.is_signer() {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
+        checks::unique(&[owner, vault, system_program])?;
 
-        if !vault.is_owned_by(&pinocchio_system::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
+        checks::signer(owner)?;
+        checks::writable(owner)?;
+        checks::not_executable(owner)?;
 
-        if vault.lamports().ne(&0) {
-            return Err(ProgramError::InvalidAccountData);
-        }
+        checks::writable(vault)?;
+        checks::owned_by(vault, &pinocchio_system::ID)?;
+        checks::not_executable(vault)?;
 
-        let (vault_key, _) = find_program_address(&[b"vault", owner.key()], &crate::ID);
-        if vault.key().ne(&vault_key) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
+        let (vault_key, _) =
+            Address::find_program_address(&[pda::VAULT_SEED, owner.address().as_ref()], program_id);
+        checks::address(vault, &vault_key)?;
+
+        checks::program(system_program, &pinocchio_system::ID)?;
 
         // Return the accounts
         Ok(Self { owner, vault })
     }
 }
+
+pub struct DepositInstructionData {
+    pub amount: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for DepositInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let amount = u64::from_le_bytes(
+            data.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        if amount.eq(&0) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { amount })
+    }
+}
+
+pub struct Deposit<'a> {
+    pub accounts: DepositAccounts<'a>,
+    pub instruction_data: DepositInstructionData,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [u8], &'a [AccountView])> for Deposit<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, data, accounts): (&'a Address, &'a [u8], &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = DepositAccounts::try_from((program_id, accounts))?;
+        let instruction_data = DepositInstructionData::try_from(data)?;
+
+        if accounts
+            .vault
+            .lamports()
+            .saturating_add(instruction_data.amount)
+            .lt(&pda::vault_rent_floor()?)
+        {
+            return Err(ProgramError::AccountNotRentExempt);
+        }
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> Deposit<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &0;
+
+    pub fn process(&self) -> ProgramResult {
+        Transfer {
+            from: self.accounts.owner,
+            to: self.accounts.vault,
+            lamports: self.instruction_data.amount,
+        }
+        .invoke()
+    }
+}
@@ -0,0 +1,107 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    AccountView, Address, ProgramResult,
+};
+
+use crate::{checks, pda, state::Config};
+
+pub struct InitializeConfigAccounts<'a> {
+    pub program_id: &'a Address,
+    pub authority: &'a AccountView,
+    pub config: &'a AccountView,
+    pub bump: u8,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [AccountView])> for InitializeConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, accounts): (&'a Address, &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let [authority, config, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Accounts Checks
+        checks::unique(&[authority, config, system_program])?;
+
+        checks::signer(authority)?;
+        checks::writable(authority)?;
+        checks::not_executable(authority)?;
+
+        checks::writable(config)?;
+
+        if config.owned_by(program_id) {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+        checks::owned_by(config, &pinocchio_system::ID)?;
+
+        let (config_key, bump) = Address::find_program_address(
+            &[Config::SEED, authority.address().as_ref()],
+            program_id,
+        );
+        checks::address(config, &config_key)?;
+
+        checks::program(system_program, &pinocchio_system::ID)?;
+
+        // Return the accounts
+        Ok(Self {
+            program_id,
+            authority,
+            config,
+            bump,
+        })
+    }
+}
+
+pub struct InitializeConfig<'a> {
+    pub accounts: InitializeConfigAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [u8], &'a [AccountView])> for InitializeConfig<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, data, accounts): (&'a Address, &'a [u8], &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let accounts = InitializeConfigAccounts::try_from((program_id, accounts))?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> InitializeConfig<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &1;
+
+    pub fn process(&self) -> ProgramResult {
+        let InitializeConfigAccounts {
+            program_id,
+            authority,
+            config,
+            bump,
+        } = self.accounts;
+
+        let bump_seed = [bump];
+        let seeds = [
+            Seed::from(Config::SEED),
+            Seed::from(authority.address().as_ref()),
+            Seed::from(&bump_seed),
+        ];
+
+        pda::create_account(
+            authority,
+            config,
+            Config::LEN,
+            program_id,
+            &[Signer::from(&seeds)],
+        )?;
+
+        let mut data = config.try_borrow_mut()?;
+        Config::init(&mut data, authority.address(), bump)
+    }
+}
@@ -0,0 +1,129 @@
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    checks,
+    errors::MeltError,
+    state::{self, Config, SpendTracker},
+};
+
+pub struct MigrateAccounts<'a> {
+    pub authority: &'a AccountView,
+    pub account: &'a AccountView,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [AccountView])> for MigrateAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, accounts): (&'a Address, &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let [authority, account, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Accounts Checks
+        checks::unique(&[authority, account, system_program])?;
+
+        checks::signer(authority)?;
+        checks::writable(authority)?;
+        checks::not_executable(authority)?;
+
+        checks::writable(account)?;
+        checks::owned_by(account, program_id)?;
+
+        checks::program(system_program, &pinocchio_system::ID)?;
+
+        // Return the accounts
+        Ok(Self { authority, account })
+    }
+}
+
+/// Upgrades a program account to the layout this program version expects.
+///
+/// Migrating an account that is already current is a no-op.
+pub struct Migrate<'a> {
+    pub accounts: MigrateAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [u8], &'a [AccountView])> for Migrate<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, data, accounts): (&'a Address, &'a [u8], &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let accounts = MigrateAccounts::try_from((program_id, accounts))?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> Migrate<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &2;
+
+    pub fn process(&self) -> ProgramResult {
+        let (discriminator, version) = state::header(&self.accounts.account.try_borrow()?)?;
+
+        match discriminator {
+            Config::DISCRIMINATOR => self.migrate_config(version),
+            SpendTracker::DISCRIMINATOR => self.migrate_spend_tracker(),
+            _ => Err(MeltError::UnknownAccountType.into()),
+        }
+    }
+
+    fn migrate_config(&self, version: u8) -> ProgramResult {
+        let account = self.accounts.account;
+
+        if Config::authority_of(&account.try_borrow()?)?.ne(self.accounts.authority.address()) {
+            return Err(MeltError::InvalidAuthority.into());
+        }
+
+        if version.eq(&Config::VERSION) {
+            return Ok(());
+        }
+
+        self.resize(Config::LEN)?;
+        Config::migrate(&mut account.try_borrow_mut()?, version)
+    }
+
+    /// Spend trackers are still at their first layout, so a current one is
+    /// left as is; `load` rejects any other version. Their authority is the
+    /// cranker they track.
+    fn migrate_spend_tracker(&self) -> ProgramResult {
+        let data = self.accounts.account.try_borrow()?;
+
+        if SpendTracker::load(&data)?
+            .cranker
+            .ne(self.accounts.authority.address())
+        {
+            return Err(MeltError::InvalidAuthority.into());
+        }
+
+        Ok(())
+    }
+
+    /// Grows the account to `len` bytes, topping up rent from the authority.
+    fn resize(&self, len: usize) -> ProgramResult {
+        let account = self.accounts.account;
+
+        let minimum_balance = Rent::get()?.minimum_balance(len);
+        if account.lamports().lt(&minimum_balance) {
+            Transfer {
+                from: self.accounts.authority,
+                to: account,
+                lamports: minimum_balance - account.lamports(),
+            }
+            .invoke()?;
+        }
+
+        account.resize(len)
+    }
+}
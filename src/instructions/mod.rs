@@ -1 +1,13 @@
 pub mod deposit;
+pub mod initialize_config;
+pub mod migrate;
+pub mod record_spend;
+pub mod set_crankers;
+pub mod tend;
+
+pub use deposit::*;
+pub use initialize_config::*;
+pub use migrate::*;
+pub use record_spend::*;
+pub use set_crankers::*;
+pub use tend::*;
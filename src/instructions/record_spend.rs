@@ -0,0 +1,177 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+
+use crate::{
+    checks,
+    errors::MeltError,
+    pda,
+    state::{Config, SpendTracker},
+};
+
+pub struct RecordSpendAccounts<'a> {
+    pub program_id: &'a Address,
+    pub cranker: &'a AccountView,
+    pub config: &'a AccountView,
+    pub tracker: &'a AccountView,
+    pub epoch: u64,
+    pub epoch_cap: u64,
+    pub bump: u8,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [AccountView])> for RecordSpendAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, accounts): (&'a Address, &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let [cranker, config, tracker, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Accounts Checks
+        checks::unique(&[cranker, config, tracker, system_program])?;
+
+        checks::signer(cranker)?;
+        checks::writable(cranker)?;
+        checks::not_executable(cranker)?;
+
+        checks::owned_by(config, program_id)?;
+
+        let epoch_cap = Config::load(&config.try_borrow()?)?
+            .cranker(cranker.address())
+            .ok_or(MeltError::UnauthorizedCranker)?
+            .epoch_cap();
+
+        checks::writable(tracker)?;
+
+        let epoch = Clock::get()?.epoch;
+        let (tracker_key, bump) = Address::find_program_address(
+            &[
+                SpendTracker::SEED,
+                config.address().as_ref(),
+                cranker.address().as_ref(),
+                &epoch.to_le_bytes(),
+            ],
+            program_id,
+        );
+        checks::address(tracker, &tracker_key)?;
+
+        checks::program(system_program, &pinocchio_system::ID)?;
+
+        // Return the accounts
+        Ok(Self {
+            program_id,
+            cranker,
+            config,
+            tracker,
+            epoch,
+            epoch_cap,
+            bump,
+        })
+    }
+}
+
+pub struct RecordSpendInstructionData {
+    pub lamports: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for RecordSpendInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let lamports = u64::from_le_bytes(
+            data.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        Ok(Self { lamports })
+    }
+}
+
+/// Adds to the calling cranker's spend counter for the current epoch.
+///
+/// The tracker is created on first use, paid for by the cranker. Programs
+/// can also CPI into this, forwarding the cranker's signature. Fails once the
+/// epoch total would exceed the cranker's cap.
+pub struct RecordSpend<'a> {
+    pub accounts: RecordSpendAccounts<'a>,
+    pub instruction_data: RecordSpendInstructionData,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [u8], &'a [AccountView])> for RecordSpend<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, data, accounts): (&'a Address, &'a [u8], &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RecordSpendAccounts::try_from((program_id, accounts))?;
+        let instruction_data = RecordSpendInstructionData::try_from(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> RecordSpend<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &4;
+
+    pub fn process(&self) -> ProgramResult {
+        let RecordSpendAccounts {
+            program_id,
+            cranker,
+            config,
+            tracker,
+            epoch,
+            epoch_cap,
+            bump,
+        } = self.accounts;
+
+        // A system-owned tracker has not been created yet, though it may
+        // already hold lamports sent to its predictable address.
+        if tracker.owned_by(&pinocchio_system::ID) {
+            let epoch_seed = epoch.to_le_bytes();
+            let bump_seed = [bump];
+            let seeds = [
+                Seed::from(SpendTracker::SEED),
+                Seed::from(config.address().as_ref()),
+                Seed::from(cranker.address().as_ref()),
+                Seed::from(&epoch_seed),
+                Seed::from(&bump_seed),
+            ];
+
+            pda::create_account(
+                cranker,
+                tracker,
+                SpendTracker::LEN,
+                program_id,
+                &[Signer::from(&seeds)],
+            )?;
+
+            SpendTracker::init(
+                &mut tracker.try_borrow_mut()?,
+                config.address(),
+                cranker.address(),
+                epoch,
+                bump,
+            )?;
+        } else {
+            checks::owned_by(tracker, program_id)?;
+        }
+
+        let mut data = tracker.try_borrow_mut()?;
+        let tracker = SpendTracker::load_mut(&mut data)?;
+        tracker.record(self.instruction_data.lamports)?;
+
+        if tracker.spent().gt(&epoch_cap) {
+            return Err(MeltError::BudgetExceeded.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use core::mem::size_of;
+
+use pinocchio::{error::ProgramError, AccountView, Address, ProgramResult};
+
+use crate::{
+    checks,
+    errors::MeltError,
+    state::{Config, Cranker, MAX_CRANKERS},
+};
+
+pub struct SetCrankersAccounts<'a> {
+    pub authority: &'a AccountView,
+    pub config: &'a AccountView,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [AccountView])> for SetCrankersAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, accounts): (&'a Address, &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let [authority, config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Accounts Checks
+        checks::unique(&[authority, config])?;
+
+        checks::signer(authority)?;
+        checks::not_executable(authority)?;
+
+        checks::writable(config)?;
+        checks::owned_by(config, program_id)?;
+
+        if Config::load(&config.try_borrow()?)?
+            .authority
+            .ne(authority.address())
+        {
+            return Err(MeltError::InvalidAuthority.into());
+        }
+
+        // Return the accounts
+        Ok(Self { authority, config })
+    }
+}
+
+pub struct SetCrankersInstructionData<'a> {
+    pub crankers: &'a [Cranker],
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetCrankersInstructionData<'a> {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() % size_of::<Cranker>() != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let len = data.len() / size_of::<Cranker>();
+        if len.gt(&MAX_CRANKERS) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // `Cranker` is made of byte arrays only, so any chunk is a valid one.
+        let crankers = unsafe { core::slice::from_raw_parts(data.as_ptr() as *const Cranker, len) };
+
+        for (i, cranker) in crankers.iter().enumerate() {
+            if crankers[i + 1..]
+                .iter()
+                .any(|c| c.address.eq(&cranker.address))
+            {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+        }
+
+        Ok(Self { crankers })
+    }
+}
+
+/// Replaces the list of crankers allowed to `RecordSpend` against a config,
+/// along with each one's per-epoch budget cap.
+pub struct SetCrankers<'a> {
+    pub accounts: SetCrankersAccounts<'a>,
+    pub instruction_data: SetCrankersInstructionData<'a>,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [u8], &'a [AccountView])> for SetCrankers<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, data, accounts): (&'a Address, &'a [u8], &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetCrankersAccounts::try_from((program_id, accounts))?;
+        let instruction_data = SetCrankersInstructionData::try_from(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'a> SetCrankers<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &3;
+
+    pub fn process(&self) -> ProgramResult {
+        let mut data = self.accounts.config.try_borrow_mut()?;
+        Config::load_mut(&mut data)?.set_crankers(self.instruction_data.crankers)
+    }
+}
@@ -0,0 +1,177 @@
+use pinocchio::{
+    cpi::{Seed, Signer},
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    checks,
+    errors::MeltError,
+    pda,
+    state::{Config, SpendTracker},
+};
+
+/// Spend trackers are kept for this many epochs after their own.
+pub const SPEND_RETENTION_EPOCHS: u64 = 4;
+
+/// Lamports paid from the vault per account tidied up.
+pub const TEND_TIP_LAMPORTS: u64 = 5_000;
+
+pub struct TendAccounts<'a> {
+    pub program_id: &'a Address,
+    pub tender: &'a AccountView,
+    pub config: &'a AccountView,
+    pub vault: &'a AccountView,
+    pub authority: Address,
+    pub bump: u8,
+    pub trackers: &'a [AccountView],
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [AccountView])> for TendAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, accounts): (&'a Address, &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        let [tender, config, vault, system_program, trackers @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Accounts Checks
+        checks::unique_with_remaining(&[tender, config, vault, system_program], trackers)?;
+
+        checks::signer(tender)?;
+        checks::writable(tender)?;
+        checks::not_executable(tender)?;
+
+        checks::owned_by(config, program_id)?;
+        let authority = Config::load(&config.try_borrow()?)?.authority;
+
+        checks::writable(vault)?;
+        checks::owned_by(vault, &pinocchio_system::ID)?;
+
+        let (vault_key, bump) =
+            Address::find_program_address(&[pda::VAULT_SEED, authority.as_ref()], program_id);
+        checks::address(vault, &vault_key)?;
+
+        checks::program(system_program, &pinocchio_system::ID)?;
+
+        // Return the accounts
+        Ok(Self {
+            program_id,
+            tender,
+            config,
+            vault,
+            authority,
+            bump,
+            trackers,
+        })
+    }
+}
+
+/// Permissionless upkeep: closes spend trackers past their retention window,
+/// returning their rent to the authority's vault, and tips the caller from
+/// the vault for each one.
+///
+/// Trackers to close are passed as the remaining accounts.
+pub struct Tend<'a> {
+    pub accounts: TendAccounts<'a>,
+}
+
+impl<'a> TryFrom<(&'a Address, &'a [u8], &'a [AccountView])> for Tend<'a> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (program_id, data, accounts): (&'a Address, &'a [u8], &'a [AccountView]),
+    ) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let accounts = TendAccounts::try_from((program_id, accounts))?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'a> Tend<'a> {
+    pub const DISCRIMINATOR: &'a u8 = &5;
+
+    pub fn process(&self) -> ProgramResult {
+        let TendAccounts {
+            tender,
+            vault,
+            authority,
+            bump,
+            trackers,
+            ..
+        } = self.accounts;
+
+        if trackers.is_empty() {
+            return Err(MeltError::NothingToTend.into());
+        }
+
+        let epoch = Clock::get()?.epoch;
+
+        for tracker in trackers {
+            self.close_tracker(tracker, epoch)?;
+        }
+
+        let available = vault.lamports().saturating_sub(pda::vault_rent_floor()?);
+        let tip = TEND_TIP_LAMPORTS
+            .saturating_mul(trackers.len() as u64)
+            .min(available);
+
+        if tip.eq(&0) {
+            return Ok(());
+        }
+
+        let bump_seed = [bump];
+        let seeds = [
+            Seed::from(pda::VAULT_SEED),
+            Seed::from(authority.as_ref()),
+            Seed::from(&bump_seed),
+        ];
+
+        Transfer {
+            from: vault,
+            to: tender,
+            lamports: tip,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])
+    }
+
+    fn close_tracker(&self, tracker: &AccountView, epoch: u64) -> ProgramResult {
+        checks::writable(tracker)?;
+        checks::owned_by(tracker, self.accounts.program_id)?;
+
+        {
+            let data = tracker.try_borrow()?;
+            let spend = SpendTracker::load(&data)?;
+
+            if spend.config.ne(self.accounts.config.address()) {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if spend
+                .epoch()
+                .saturating_add(SPEND_RETENTION_EPOCHS)
+                .ge(&epoch)
+            {
+                return Err(MeltError::AccountNotExpired.into());
+            }
+        }
+
+        let vault = self.accounts.vault;
+        vault.set_lamports(
+            vault
+                .lamports()
+                .checked_add(tracker.lamports())
+                .ok_or(ProgramError::ArithmeticOverflow)?,
+        );
+
+        tracker.close()
+    }
+}
@@ -1,6 +1,14 @@
-use pinocchio::{entrypoint, AccountView, Address, ProgramResult};
-use solana_program_log::log;
+use pinocchio::{entrypoint, error::ProgramError, AccountView, Address, ProgramResult};
 
+pub mod checks;
+pub mod errors;
+pub mod instructions;
+pub mod pda;
+pub mod state;
+
+use instructions::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -8,6 +16,25 @@ pub fn process_instruction(
     accounts: &[AccountView],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    log!("Hello from my pinocchio program!");
-    Ok(())
+    match instruction_data.split_first() {
+        Some((Deposit::DISCRIMINATOR, data)) => {
+            Deposit::try_from((program_id, data, accounts))?.process()
+        }
+        Some((InitializeConfig::DISCRIMINATOR, data)) => {
+            InitializeConfig::try_from((program_id, data, accounts))?.process()
+        }
+        Some((Migrate::DISCRIMINATOR, data)) => {
+            Migrate::try_from((program_id, data, accounts))?.process()
+        }
+        Some((SetCrankers::DISCRIMINATOR, data)) => {
+            SetCrankers::try_from((program_id, data, accounts))?.process()
+        }
+        Some((RecordSpend::DISCRIMINATOR, data)) => {
+            RecordSpend::try_from((program_id, data, accounts))?.process()
+        }
+        Some((Tend::DISCRIMINATOR, data)) => {
+            Tend::try_from((program_id, data, accounts))?.process()
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
 }
@@ -0,0 +1,63 @@
+use pinocchio::{
+    cpi::Signer,
+    error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    AccountView, Address, ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+/// Seed of an authority's lamport vault, at `["vault", authority]`.
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Lowest balance the vault may be left with.
+///
+/// The vault is a plain system account holding no data. It can be funded
+/// ahead of time by deposits or reclaimed rent, and must stay rent exempt
+/// whenever lamports move in or out.
+pub fn vault_rent_floor() -> Result<u64, ProgramError> {
+    Ok(Rent::get()?.minimum_balance(0))
+}
+
+/// Creates a rent-exempt PDA of `space` bytes owned by `owner`.
+///
+/// `CreateAccount` refuses any address that already holds lamports, and PDA
+/// addresses are predictable, so anyone could block one by funding it first.
+/// A pre-funded address is instead topped up, then allocated and assigned
+/// with the PDA's own signature.
+pub fn create_account(
+    payer: &AccountView,
+    account: &AccountView,
+    space: usize,
+    owner: &Address,
+    signers: &[Signer],
+) -> ProgramResult {
+    let minimum_balance = Rent::get()?.minimum_balance(space);
+
+    if account.lamports().eq(&0) {
+        return CreateAccount {
+            from: payer,
+            to: account,
+            lamports: minimum_balance,
+            space: space as u64,
+            owner,
+        }
+        .invoke_signed(signers);
+    }
+
+    if account.lamports().lt(&minimum_balance) {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: minimum_balance - account.lamports(),
+        }
+        .invoke()?;
+    }
+
+    Allocate {
+        account,
+        space: space as u64,
+    }
+    .invoke_signed(signers)?;
+
+    Assign { account, owner }.invoke_signed(signers)
+}
@@ -0,0 +1,253 @@
+use core::mem::size_of;
+
+use pinocchio::{error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    errors::MeltError,
+    state::{check, header, HEADER_LEN},
+};
+
+/// Maximum number of crankers a single config can authorize.
+pub const MAX_CRANKERS: usize = 8;
+
+/// An allow-listed cranker and the most it may spend per epoch.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct Cranker {
+    pub address: Address,
+    epoch_cap: [u8; 8],
+}
+
+impl Cranker {
+    pub fn new(address: Address, epoch_cap: u64) -> Self {
+        Self {
+            address,
+            epoch_cap: epoch_cap.to_le_bytes(),
+        }
+    }
+
+    pub fn epoch_cap(&self) -> u64 {
+        u64::from_le_bytes(self.epoch_cap)
+    }
+}
+
+/// Treasury configuration, one per authority at `["config", authority]`.
+///
+/// `authority` stays right after the header in every version so it can be
+/// checked before the rest of the layout is understood.
+#[repr(C)]
+pub struct Config {
+    discriminator: u8,
+    version: u8,
+    pub authority: Address,
+    pub bump: u8,
+    cranker_count: u8,
+    crankers: [Cranker; MAX_CRANKERS],
+}
+
+impl Config {
+    pub const DISCRIMINATOR: u8 = 1;
+
+    pub const VERSION: u8 = 3;
+
+    pub const LEN: usize = size_of::<Self>();
+
+    pub const SEED: &'static [u8] = b"config";
+
+    /// Length of the version 1 layout, which ended at `bump`.
+    const V1_LEN: usize = HEADER_LEN + size_of::<Address>() + size_of::<u8>();
+
+    pub fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        check(data, Self::DISCRIMINATOR, Self::VERSION, Self::LEN)?;
+        Ok(unsafe { &*(data.as_ptr() as *const Self) })
+    }
+
+    pub fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        check(data, Self::DISCRIMINATOR, Self::VERSION, Self::LEN)?;
+        Ok(unsafe { &mut *(data.as_mut_ptr() as *mut Self) })
+    }
+
+    pub fn init(data: &mut [u8], authority: &Address, bump: u8) -> ProgramResult {
+        if data.len().ne(&Self::LEN) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        data.fill(0);
+
+        let config = unsafe { &mut *(data.as_mut_ptr() as *mut Self) };
+        config.discriminator = Self::DISCRIMINATOR;
+        config.version = Self::VERSION;
+        config.authority = *authority;
+        config.bump = bump;
+
+        Ok(())
+    }
+
+    /// Reads the authority of a config account of any version.
+    pub fn authority_of(data: &[u8]) -> Result<&Address, ProgramError> {
+        let (discriminator, _) = header(data)?;
+        if discriminator.ne(&Self::DISCRIMINATOR) {
+            return Err(MeltError::UnknownAccountType.into());
+        }
+
+        let authority = data
+            .get(HEADER_LEN..HEADER_LEN + size_of::<Address>())
+            .ok_or(ProgramError::InvalidAccountData)?;
+        Ok(unsafe { &*(authority.as_ptr() as *const Address) })
+    }
+
+    /// Upgrades `data`, already resized to [`Config::LEN`], from layout
+    /// version `from` to [`Config::VERSION`].
+    pub fn migrate(data: &mut [u8], from: u8) -> ProgramResult {
+        if from.eq(&0) || from.gt(&Self::VERSION) || data.len().ne(&Self::LEN) {
+            return Err(MeltError::UnsupportedAccountVersion.into());
+        }
+
+        // Each step upgrades the layout by exactly one version.
+        if from.lt(&2) {
+            // v2 appends an empty cranker allow-list.
+            data[Self::V1_LEN..].fill(0);
+        }
+
+        if from.lt(&3) {
+            // v3 widens each allow-list slot with an epoch budget cap. Slots
+            // are moved back to front so none is overwritten before it is
+            // read, and crankers authorized before caps existed stay uncapped.
+            let count = data[Self::V1_LEN] as usize;
+            let start = Self::V1_LEN + size_of::<u8>();
+
+            for i in (0..MAX_CRANKERS).rev() {
+                let old = start + i * size_of::<Address>();
+                let new = start + i * size_of::<Cranker>();
+                data.copy_within(old..old + size_of::<Address>(), new);
+
+                let cap = if i.lt(&count) { u64::MAX } else { 0 };
+                data[new + size_of::<Address>()..new + size_of::<Cranker>()]
+                    .copy_from_slice(&cap.to_le_bytes());
+            }
+        }
+
+        data[1] = Self::VERSION;
+        Ok(())
+    }
+
+    pub fn crankers(&self) -> &[Cranker] {
+        &self.crankers[..self.cranker_count as usize]
+    }
+
+    pub fn cranker(&self, address: &Address) -> Option<&Cranker> {
+        self.crankers().iter().find(|c| c.address.eq(address))
+    }
+
+    /// Replaces the cranker allow-list.
+    pub fn set_crankers(&mut self, crankers: &[Cranker]) -> ProgramResult {
+        if crankers.len().gt(&MAX_CRANKERS) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        self.crankers = [Cranker::default(); MAX_CRANKERS];
+        self.crankers[..crankers.len()].copy_from_slice(crankers);
+        self.cranker_count = crankers.len() as u8;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AUTHORITY: Address = Address::new_from_array([7; 32]);
+
+    const BUMP: u8 = 254;
+
+    fn v1() -> Vec<u8> {
+        let mut data = vec![Config::DISCRIMINATOR, 1];
+        data.extend_from_slice(AUTHORITY.as_ref());
+        data.push(BUMP);
+        data
+    }
+
+    fn v2(crankers: &[Address]) -> Vec<u8> {
+        let mut data = v1();
+        data[1] = 2;
+        data.push(crankers.len() as u8);
+        for i in 0..MAX_CRANKERS {
+            let slot = crankers.get(i).unwrap_or(&Address::new_from_array([0; 32]));
+            data.extend_from_slice(slot.as_ref());
+        }
+        data
+    }
+
+    fn crankers(n: usize) -> Vec<Address> {
+        (1..=n as u8)
+            .map(|i| Address::new_from_array([i; 32]))
+            .collect()
+    }
+
+    /// Grows `data` the way `Migrate` does, then migrates it. The new bytes
+    /// are filled with junk to show the migration writes every one of them.
+    fn migrate(mut data: Vec<u8>, from: u8) -> Vec<u8> {
+        data.resize(Config::LEN, 0xAA);
+        Config::migrate(&mut data, from).unwrap();
+        data
+    }
+
+    #[test]
+    fn v1_layout_needs_migration() {
+        assert_eq!(
+            Config::load(&v1()).err(),
+            Some(MeltError::AccountNeedsMigration.into())
+        );
+    }
+
+    #[test]
+    fn migrates_v1() {
+        let data = migrate(v1(), 1);
+        let config = Config::load(&data).unwrap();
+
+        assert_eq!(config.authority, AUTHORITY);
+        assert_eq!(config.bump, BUMP);
+        assert!(config.crankers().is_empty());
+    }
+
+    fn assert_migrates_v2(n: usize) {
+        let expected = crankers(n);
+        let data = migrate(v2(&expected), 2);
+        let config = Config::load(&data).unwrap();
+
+        assert_eq!(config.authority, AUTHORITY);
+        assert_eq!(config.bump, BUMP);
+        assert_eq!(config.crankers().len(), n);
+        for (cranker, address) in config.crankers().iter().zip(&expected) {
+            assert_eq!(&cranker.address, address);
+            assert_eq!(cranker.epoch_cap(), u64::MAX);
+        }
+    }
+
+    #[test]
+    fn migrates_v2_without_crankers() {
+        assert_migrates_v2(0);
+    }
+
+    #[test]
+    fn migrates_v2_with_some_crankers() {
+        assert_migrates_v2(3);
+    }
+
+    #[test]
+    fn migrates_v2_with_max_crankers() {
+        assert_migrates_v2(MAX_CRANKERS);
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        let mut data = vec![0; Config::LEN];
+        for from in [0, Config::VERSION + 1] {
+            assert_eq!(
+                Config::migrate(&mut data, from).err(),
+                Some(MeltError::UnsupportedAccountVersion.into())
+            );
+        }
+    }
+}
@@ -0,0 +1,36 @@
+pub mod config;
+pub mod spend_tracker;
+
+pub use config::*;
+pub use spend_tracker::*;
+
+use pinocchio::{error::ProgramError, ProgramResult};
+
+use crate::errors::MeltError;
+
+/// Every program account starts with a type discriminator followed by the
+/// version of its layout, so older accounts can be recognised and migrated.
+pub const HEADER_LEN: usize = 2;
+
+/// Returns the `(discriminator, version)` header of a program account.
+pub fn header(data: &[u8]) -> Result<(u8, u8), ProgramError> {
+    match data {
+        [discriminator, version, ..] => Ok((*discriminator, *version)),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Checks that `data` holds an account of the given type at its current layout.
+pub fn check(data: &[u8], discriminator: u8, version: u8, len: usize) -> ProgramResult {
+    let header = header(data)?;
+    if header.0.ne(&discriminator) {
+        return Err(MeltError::UnknownAccountType.into());
+    }
+    if header.1.lt(&version) {
+        return Err(MeltError::AccountNeedsMigration.into());
+    }
+    if header.1.gt(&version) || data.len().ne(&len) {
+        return Err(MeltError::UnsupportedAccountVersion.into());
+    }
+    Ok(())
+}
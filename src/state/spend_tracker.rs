@@ -0,0 +1,79 @@
+use core::mem::size_of;
+
+use pinocchio::{error::ProgramError, Address, ProgramResult};
+
+use crate::state::check;
+
+/// Lamports a cranker reported spending in one epoch, at
+/// `["spend", config, cranker, epoch.to_le_bytes()]`.
+#[repr(C)]
+pub struct SpendTracker {
+    discriminator: u8,
+    version: u8,
+    pub config: Address,
+    pub cranker: Address,
+    epoch: [u8; 8],
+    spent: [u8; 8],
+    pub bump: u8,
+}
+
+impl SpendTracker {
+    pub const DISCRIMINATOR: u8 = 2;
+
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = size_of::<Self>();
+
+    pub const SEED: &'static [u8] = b"spend";
+
+    pub fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        check(data, Self::DISCRIMINATOR, Self::VERSION, Self::LEN)?;
+        Ok(unsafe { &*(data.as_ptr() as *const Self) })
+    }
+
+    pub fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        check(data, Self::DISCRIMINATOR, Self::VERSION, Self::LEN)?;
+        Ok(unsafe { &mut *(data.as_mut_ptr() as *mut Self) })
+    }
+
+    pub fn init(
+        data: &mut [u8],
+        config: &Address,
+        cranker: &Address,
+        epoch: u64,
+        bump: u8,
+    ) -> ProgramResult {
+        if data.len().ne(&Self::LEN) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        data.fill(0);
+
+        let tracker = unsafe { &mut *(data.as_mut_ptr() as *mut Self) };
+        tracker.discriminator = Self::DISCRIMINATOR;
+        tracker.version = Self::VERSION;
+        tracker.config = *config;
+        tracker.cranker = *cranker;
+        tracker.epoch = epoch.to_le_bytes();
+        tracker.bump = bump;
+
+        Ok(())
+    }
+
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    pub fn spent(&self) -> u64 {
+        u64::from_le_bytes(self.spent)
+    }
+
+    pub fn record(&mut self, lamports: u64) -> ProgramResult {
+        let spent = self
+            .spent()
+            .checked_add(lamports)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.spent = spent.to_le_bytes();
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+#![allow(dead_code)]
+
+use melt::{
+    instructions::*,
+    pda::VAULT_SEED,
+    state::{Config, Cranker, SpendTracker},
+};
+use mollusk_svm::{
+    program,
+    result::{Check, InstructionResult},
+    Mollusk,
+};
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+/// Loads `melt.so` from `SBF_OUT_DIR` under a fresh program id.
+pub fn setup() -> (Mollusk, Pubkey) {
+    let program_id = Pubkey::new_unique();
+    (Mollusk::new(&program_id, "melt"), program_id)
+}
+
+pub fn system_program() -> (Pubkey, Account) {
+    program::keyed_account_for_system_program()
+}
+
+pub fn system_account(lamports: u64) -> Account {
+    Account::new(lamports, 0, &solana_sdk_ids::system_program::ID)
+}
+
+pub fn vault_address(program_id: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_SEED, owner.as_ref()], program_id).0
+}
+
+pub fn deposit(program_id: &Pubkey, owner: &Pubkey, vault: &Pubkey, amount: u64) -> Instruction {
+    let mut data = vec![*Deposit::DISCRIMINATOR];
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*owner, true),
+            AccountMeta::new(*vault, false),
+            AccountMeta::new_readonly(system_program().0, false),
+        ],
+    )
+}
+
+pub const OWNER_LAMPORTS: u64 = 10_000_000_000;
+
+/// A `Deposit` of the smallest amount that leaves an empty vault rent exempt.
+pub struct DepositFixture {
+    pub mollusk: Mollusk,
+    pub program_id: Pubkey,
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+}
+
+impl DepositFixture {
+    pub fn new() -> Self {
+        let (mollusk, program_id) = setup();
+        let owner = Pubkey::new_unique();
+        let vault = vault_address(&program_id, &owner);
+        let amount = mollusk.sysvars.rent.minimum_balance(0);
+
+        Self {
+            mollusk,
+            program_id,
+            owner,
+            vault,
+            amount,
+        }
+    }
+
+    pub fn accounts(&self) -> Vec<(Pubkey, Account)> {
+        vec![
+            (self.owner, system_account(OWNER_LAMPORTS)),
+            (self.vault, system_account(0)),
+            system_program(),
+        ]
+    }
+
+    pub fn run(
+        &self,
+        ix: &Instruction,
+        accounts: &[(Pubkey, Account)],
+        checks: &[Check],
+    ) -> InstructionResult {
+        self.mollusk
+            .process_and_validate_instruction(ix, accounts, checks)
+    }
+}
+
+pub fn config_address(program_id: &Pubkey, authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[Config::SEED, authority.as_ref()], program_id).0
+}
+
+pub fn initialize_config(program_id: &Pubkey, authority: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        *program_id,
+        &[*InitializeConfig::DISCRIMINATOR],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(config_address(program_id, authority), false),
+            AccountMeta::new_readonly(system_program().0, false),
+        ],
+    )
+}
+
+/// An initialized config owned by `program_id` with the given crankers and
+/// their epoch caps.
+pub fn config_account(
+    mollusk: &Mollusk,
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    crankers: &[(Pubkey, u64)],
+) -> Account {
+    let (_, bump) = Pubkey::find_program_address(&[Config::SEED, authority.as_ref()], program_id);
+
+    let mut data = vec![0; Config::LEN];
+    Config::init(&mut data, authority, bump).unwrap();
+
+    let crankers: Vec<Cranker> = crankers
+        .iter()
+        .map(|(address, cap)| Cranker::new(*address, *cap))
+        .collect();
+    Config::load_mut(&mut data)
+        .unwrap()
+        .set_crankers(&crankers)
+        .unwrap();
+
+    Account {
+        lamports: mollusk.sysvars.rent.minimum_balance(Config::LEN),
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn migrate(program_id: &Pubkey, authority: &Pubkey, account: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        *program_id,
+        &[*Migrate::DISCRIMINATOR],
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*account, false),
+            AccountMeta::new_readonly(system_program().0, false),
+        ],
+    )
+}
+
+pub fn set_crankers(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    config: &Pubkey,
+    crankers: &[(Pubkey, u64)],
+) -> Instruction {
+    let mut data = vec![*SetCrankers::DISCRIMINATOR];
+    for (address, cap) in crankers {
+        data.extend_from_slice(address.as_ref());
+        data.extend_from_slice(&cap.to_le_bytes());
+    }
+
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*config, false),
+        ],
+    )
+}
+
+pub fn tracker_address(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    cranker: &Pubkey,
+    epoch: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            SpendTracker::SEED,
+            config.as_ref(),
+            cranker.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+pub fn record_spend(
+    program_id: &Pubkey,
+    cranker: &Pubkey,
+    config: &Pubkey,
+    tracker: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    let mut data = vec![*RecordSpend::DISCRIMINATOR];
+    data.extend_from_slice(&lamports.to_le_bytes());
+
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*cranker, true),
+            AccountMeta::new_readonly(*config, false),
+            AccountMeta::new(*tracker, false),
+            AccountMeta::new_readonly(system_program().0, false),
+        ],
+    )
+}
+
+/// A spend tracker owned by `program_id` for `epoch`, with `spent` lamports
+/// already recorded.
+pub fn tracker_account(
+    mollusk: &Mollusk,
+    program_id: &Pubkey,
+    config: &Pubkey,
+    cranker: &Pubkey,
+    epoch: u64,
+    spent: u64,
+) -> Account {
+    let mut data = vec![0; SpendTracker::LEN];
+    SpendTracker::init(&mut data, config, cranker, epoch, 255).unwrap();
+    SpendTracker::load_mut(&mut data)
+        .unwrap()
+        .record(spent)
+        .unwrap();
+
+    Account {
+        lamports: mollusk.sysvars.rent.minimum_balance(SpendTracker::LEN),
+        data,
+        owner: *program_id,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn tend(
+    program_id: &Pubkey,
+    tender: &Pubkey,
+    config: &Pubkey,
+    vault: &Pubkey,
+    trackers: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*tender, true),
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*vault, false),
+        AccountMeta::new_readonly(system_program().0, false),
+    ];
+    accounts.extend(trackers.iter().map(|t| AccountMeta::new(*t, false)));
+
+    Instruction::new_with_bytes(*program_id, &[*Tend::DISCRIMINATOR], accounts)
+}
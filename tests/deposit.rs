@@ -0,0 +1,39 @@
+mod common;
+
+use common::*;
+use mollusk_svm::result::Check;
+
+#[test]
+fn deposit_succeeds() {
+    let t = DepositFixture::new();
+    let ix = deposit(&t.program_id, &t.owner, &t.vault, t.amount);
+
+    t.run(
+        &ix,
+        &t.accounts(),
+        &[
+            Check::success(),
+            Check::account(&t.vault).lamports(t.amount).build(),
+        ],
+    );
+}
+
+/// The vault also receives rent from trackers closed by `Tend`, so it may
+/// already hold lamports when deposited into.
+#[test]
+fn deposit_tops_up_funded_vault() {
+    let t = DepositFixture::new();
+    let ix = deposit(&t.program_id, &t.owner, &t.vault, 1);
+
+    let mut accounts = t.accounts();
+    accounts[1].1 = system_account(t.amount);
+
+    t.run(
+        &ix,
+        &accounts,
+        &[
+            Check::success(),
+            Check::account(&t.vault).lamports(t.amount + 1).build(),
+        ],
+    );
+}
@@ -0,0 +1,61 @@
+mod common;
+
+use common::*;
+use melt::state::Config;
+use mollusk_svm::result::Check;
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const AUTHORITY_LAMPORTS: u64 = 10_000_000_000;
+
+#[test]
+fn initializes_config() {
+    let (mollusk, program_id) = setup();
+    let authority = Pubkey::new_unique();
+    let config = config_address(&program_id, &authority);
+
+    let minimum_balance = mollusk.sysvars.rent.minimum_balance(Config::LEN);
+
+    let result = mollusk.process_and_validate_instruction(
+        &initialize_config(&program_id, &authority),
+        &[
+            (authority, system_account(AUTHORITY_LAMPORTS)),
+            (config, system_account(0)),
+            system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&config)
+                .owner(&program_id)
+                .space(Config::LEN)
+                .lamports(minimum_balance)
+                .build(),
+        ],
+    );
+
+    let account = result.get_account(&config).unwrap();
+    assert_eq!(Config::load(&account.data).unwrap().authority, authority);
+}
+
+#[test]
+fn rejects_initialized_config() {
+    let (mollusk, program_id) = setup();
+    let authority = Pubkey::new_unique();
+    let config = config_address(&program_id, &authority);
+
+    let minimum_balance = mollusk.sysvars.rent.minimum_balance(Config::LEN);
+
+    mollusk.process_and_validate_instruction(
+        &initialize_config(&program_id, &authority),
+        &[
+            (authority, system_account(AUTHORITY_LAMPORTS)),
+            (
+                config,
+                Account::new(minimum_balance, Config::LEN, &program_id),
+            ),
+            system_program(),
+        ],
+        &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    );
+}
@@ -0,0 +1,274 @@
+mod common;
+
+use common::*;
+use melt::{
+    errors::MeltError,
+    state::{Config, MAX_CRANKERS},
+};
+use mollusk_svm::{result::Check, Mollusk};
+use solana_account::Account;
+use solana_instruction::AccountMeta;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const AUTHORITY_LAMPORTS: u64 = 10_000_000_000;
+
+struct MigrateFixture {
+    mollusk: Mollusk,
+    program_id: Pubkey,
+    authority: Pubkey,
+    config: Pubkey,
+    bump: u8,
+}
+
+impl MigrateFixture {
+    fn new() -> Self {
+        let (mollusk, program_id) = setup();
+        let authority = Pubkey::new_unique();
+        let (config, bump) =
+            Pubkey::find_program_address(&[Config::SEED, authority.as_ref()], &program_id);
+
+        Self {
+            mollusk,
+            program_id,
+            authority,
+            config,
+            bump,
+        }
+    }
+
+    /// A rent-exempt config account in the version 1 layout.
+    fn v1(&self) -> Account {
+        let mut data = vec![Config::DISCRIMINATOR, 1];
+        data.extend_from_slice(self.authority.as_ref());
+        data.push(self.bump);
+        self.account(data)
+    }
+
+    /// A rent-exempt config account in the version 2 layout, whose
+    /// allow-list held addresses only.
+    fn v2(&self, crankers: &[Pubkey]) -> Account {
+        let mut data = self.v1().data;
+        data[1] = 2;
+        data.push(crankers.len() as u8);
+        for i in 0..MAX_CRANKERS {
+            data.extend_from_slice(crankers.get(i).unwrap_or(&Pubkey::default()).as_ref());
+        }
+        self.account(data)
+    }
+
+    fn account(&self, data: Vec<u8>) -> Account {
+        Account {
+            lamports: self.mollusk.sysvars.rent.minimum_balance(data.len()),
+            data,
+            owner: self.program_id,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    fn accounts(&self, config: Account) -> Vec<(Pubkey, Account)> {
+        vec![
+            (self.authority, system_account(AUTHORITY_LAMPORTS)),
+            (self.config, config),
+            system_program(),
+        ]
+    }
+
+    /// Migrates `config` and checks it ends up current, rent exempt at the
+    /// new size, with the top-up paid by the authority.
+    fn assert_migrates(&self, config: Account) -> Vec<u8> {
+        let minimum_balance = self.mollusk.sysvars.rent.minimum_balance(Config::LEN);
+        let top_up = minimum_balance - config.lamports;
+
+        let result = self.mollusk.process_and_validate_instruction(
+            &migrate(&self.program_id, &self.authority, &self.config),
+            &self.accounts(config),
+            &[
+                Check::success(),
+                Check::account(&self.config)
+                    .owner(&self.program_id)
+                    .space(Config::LEN)
+                    .lamports(minimum_balance)
+                    .build(),
+                Check::account(&self.authority)
+                    .lamports(AUTHORITY_LAMPORTS - top_up)
+                    .build(),
+            ],
+        );
+
+        let data = result.get_account(&self.config).unwrap().data.clone();
+        let config = Config::load(&data).unwrap();
+        assert_eq!(config.authority, self.authority);
+        assert_eq!(config.bump, self.bump);
+        data
+    }
+}
+
+#[test]
+fn migrates_v1_config() {
+    let t = MigrateFixture::new();
+
+    let data = t.assert_migrates(t.v1());
+
+    assert!(Config::load(&data).unwrap().crankers().is_empty());
+}
+
+#[test]
+fn migrates_v2_config() {
+    let t = MigrateFixture::new();
+    let crankers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let data = t.assert_migrates(t.v2(&crankers));
+
+    let config = Config::load(&data).unwrap();
+    assert_eq!(config.crankers().len(), crankers.len());
+    for (cranker, address) in config.crankers().iter().zip(&crankers) {
+        assert_eq!(&cranker.address, address);
+        assert_eq!(cranker.epoch_cap(), u64::MAX);
+    }
+}
+
+#[test]
+fn leaves_current_config_untouched() {
+    let t = MigrateFixture::new();
+    let config = config_account(
+        &t.mollusk,
+        &t.program_id,
+        &t.authority,
+        &[(Pubkey::new_unique(), 1_000)],
+    );
+
+    t.mollusk.process_and_validate_instruction(
+        &migrate(&t.program_id, &t.authority, &t.config),
+        &t.accounts(config.clone()),
+        &[
+            Check::success(),
+            Check::account(&t.config)
+                .data(&config.data)
+                .lamports(config.lamports)
+                .build(),
+            Check::account(&t.authority)
+                .lamports(AUTHORITY_LAMPORTS)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn rejects_wrong_authority() {
+    let t = MigrateFixture::new();
+    let impostor = Pubkey::new_unique();
+
+    let mut accounts = t.accounts(t.v1());
+    accounts[0].0 = impostor;
+
+    t.mollusk.process_and_validate_instruction(
+        &migrate(&t.program_id, &impostor, &t.config),
+        &accounts,
+        &[Check::err(ProgramError::from(MeltError::InvalidAuthority))],
+    );
+}
+
+#[test]
+fn rejects_missing_signer() {
+    let t = MigrateFixture::new();
+    let mut ix = migrate(&t.program_id, &t.authority, &t.config);
+    ix.accounts[0] = AccountMeta::new(t.authority, false);
+
+    t.mollusk.process_and_validate_instruction(
+        &ix,
+        &t.accounts(t.v1()),
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn rejects_read_only_authority() {
+    let t = MigrateFixture::new();
+    let mut ix = migrate(&t.program_id, &t.authority, &t.config);
+    ix.accounts[0] = AccountMeta::new_readonly(t.authority, true);
+
+    t.mollusk.process_and_validate_instruction(
+        &ix,
+        &t.accounts(t.v1()),
+        &[Check::err(ProgramError::Immutable)],
+    );
+}
+
+#[test]
+fn rejects_foreign_owned_account() {
+    let t = MigrateFixture::new();
+    let mut config = t.v1();
+    config.owner = Pubkey::new_unique();
+
+    t.mollusk.process_and_validate_instruction(
+        &migrate(&t.program_id, &t.authority, &t.config),
+        &t.accounts(config),
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn rejects_wrong_system_program() {
+    let t = MigrateFixture::new();
+    let fake = Pubkey::new_unique();
+    let mut ix = migrate(&t.program_id, &t.authority, &t.config);
+    ix.accounts[2] = AccountMeta::new_readonly(fake, false);
+
+    let mut accounts = t.accounts(t.v1());
+    accounts[2] = (fake, Account::new(0, 0, &Pubkey::new_unique()));
+
+    t.mollusk.process_and_validate_instruction(
+        &ix,
+        &accounts,
+        &[Check::err(ProgramError::IncorrectProgramId)],
+    );
+}
+
+#[test]
+fn leaves_current_tracker_untouched() {
+    let (mollusk, program_id) = setup();
+    let cranker = Pubkey::new_unique();
+    let config = Pubkey::new_unique();
+    let tracker = tracker_address(&program_id, &config, &cranker, 0, 0);
+    let account = tracker_account(&mollusk, &program_id, &config, &cranker, 0, 0);
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program_id, &cranker, &tracker),
+        &[
+            (cranker, system_account(AUTHORITY_LAMPORTS)),
+            (tracker, account.clone()),
+            system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&tracker)
+                .data(&account.data)
+                .lamports(account.lamports)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn rejects_tracker_of_another_cranker() {
+    let (mollusk, program_id) = setup();
+    let cranker = Pubkey::new_unique();
+    let impostor = Pubkey::new_unique();
+    let config = Pubkey::new_unique();
+    let tracker = tracker_address(&program_id, &config, &cranker, 0, 0);
+
+    mollusk.process_and_validate_instruction(
+        &migrate(&program_id, &impostor, &tracker),
+        &[
+            (impostor, system_account(AUTHORITY_LAMPORTS)),
+            (
+                tracker,
+                tracker_account(&mollusk, &program_id, &config, &cranker, 0, 0),
+            ),
+            system_program(),
+        ],
+        &[Check::err(ProgramError::from(MeltError::InvalidAuthority))],
+    );
+}
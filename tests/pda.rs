@@ -0,0 +1,47 @@
+mod common;
+
+use common::*;
+use melt::state::Config;
+use mollusk_svm::result::Check;
+use solana_pubkey::Pubkey;
+
+const AUTHORITY_LAMPORTS: u64 = 10_000_000_000;
+
+/// `pda::create_account` on addresses funded before the program created
+/// them, driven through `InitializeConfig`. Every handler creating a PDA
+/// shares this path.
+#[test]
+fn creates_account_at_pre_funded_address() {
+    let (mollusk, program_id) = setup();
+    let minimum_balance = mollusk.sysvars.rent.minimum_balance(Config::LEN);
+
+    for lamports in [
+        1,
+        minimum_balance - 1,
+        minimum_balance,
+        minimum_balance + 1_000_000_000,
+    ] {
+        let authority = Pubkey::new_unique();
+        let config = config_address(&program_id, &authority);
+
+        mollusk.process_and_validate_instruction(
+            &initialize_config(&program_id, &authority),
+            &[
+                (authority, system_account(AUTHORITY_LAMPORTS)),
+                (config, system_account(lamports)),
+                system_program(),
+            ],
+            &[
+                Check::success(),
+                Check::account(&config)
+                    .owner(&program_id)
+                    .space(Config::LEN)
+                    .lamports(minimum_balance.max(lamports))
+                    .build(),
+                Check::account(&authority)
+                    .lamports(AUTHORITY_LAMPORTS - minimum_balance.saturating_sub(lamports))
+                    .build(),
+            ],
+        );
+    }
+}
@@ -0,0 +1,228 @@
+mod common;
+
+use common::*;
+use melt::{errors::MeltError, state::SpendTracker};
+use mollusk_svm::{result::Check, Mollusk};
+use solana_account::Account;
+use solana_instruction::Instruction;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const CRANKER_LAMPORTS: u64 = 10_000_000_000;
+
+struct RecordSpendFixture {
+    mollusk: Mollusk,
+    program_id: Pubkey,
+    authority: Pubkey,
+    cranker: Pubkey,
+    config: Pubkey,
+    tracker: Pubkey,
+    epoch_cap: u64,
+}
+
+impl RecordSpendFixture {
+    /// An allow-listed cranker that may spend `epoch_cap` per epoch.
+    fn new(epoch_cap: u64) -> Self {
+        let (mollusk, program_id) = setup();
+        let authority = Pubkey::new_unique();
+        let cranker = Pubkey::new_unique();
+        let config = config_address(&program_id, &authority);
+        let tracker = tracker_address(&program_id, &config, &cranker, mollusk.sysvars.clock.epoch);
+
+        Self {
+            mollusk,
+            program_id,
+            authority,
+            cranker,
+            config,
+            tracker,
+            epoch_cap,
+        }
+    }
+
+    fn record(&self, lamports: u64) -> Instruction {
+        record_spend(
+            &self.program_id,
+            &self.cranker,
+            &self.config,
+            &self.tracker,
+            lamports,
+        )
+    }
+
+    /// A tracker for the current epoch with `spent` already recorded.
+    fn tracker_account(&self, spent: u64) -> Account {
+        tracker_account(
+            &self.mollusk,
+            &self.program_id,
+            &self.config,
+            &self.cranker,
+            self.mollusk.sysvars.clock.epoch,
+            spent,
+        )
+    }
+
+    fn accounts(&self, tracker: Account) -> Vec<(Pubkey, Account)> {
+        vec![
+            (self.cranker, system_account(CRANKER_LAMPORTS)),
+            (
+                self.config,
+                config_account(
+                    &self.mollusk,
+                    &self.program_id,
+                    &self.authority,
+                    &[(self.cranker, self.epoch_cap)],
+                ),
+            ),
+            (self.tracker, tracker),
+            system_program(),
+        ]
+    }
+
+    /// Records `lamports` against `tracker` and returns the new epoch total.
+    fn spent_after(&self, tracker: Account, lamports: u64) -> u64 {
+        let result = self.mollusk.process_and_validate_instruction(
+            &self.record(lamports),
+            &self.accounts(tracker),
+            &[
+                Check::success(),
+                Check::account(&self.tracker)
+                    .owner(&self.program_id)
+                    .space(SpendTracker::LEN)
+                    .build(),
+            ],
+        );
+
+        let account = result.get_account(&self.tracker).unwrap();
+        SpendTracker::load(&account.data).unwrap().spent()
+    }
+}
+
+#[test]
+fn creates_tracker() {
+    let t = RecordSpendFixture::new(u64::MAX);
+    assert_eq!(t.spent_after(system_account(0), 42), 42);
+}
+
+/// The pre-funded case of `pda::create_account` is covered in `tests/pda.rs`;
+/// this only shows `RecordSpend` goes through it.
+#[test]
+fn creates_tracker_at_pre_funded_address() {
+    let t = RecordSpendFixture::new(u64::MAX);
+    assert_eq!(t.spent_after(system_account(1_000), 42), 42);
+}
+
+#[test]
+fn adds_to_existing_tracker() {
+    let t = RecordSpendFixture::new(u64::MAX);
+    let tracker = t.tracker_account(100);
+
+    // No rent is paid again; only the counter moves.
+    let result = t.mollusk.process_and_validate_instruction(
+        &t.record(42),
+        &t.accounts(tracker.clone()),
+        &[
+            Check::success(),
+            Check::account(&t.tracker)
+                .lamports(tracker.lamports)
+                .build(),
+            Check::account(&t.cranker)
+                .lamports(CRANKER_LAMPORTS)
+                .build(),
+        ],
+    );
+
+    let account = result.get_account(&t.tracker).unwrap();
+    assert_eq!(SpendTracker::load(&account.data).unwrap().spent(), 142);
+}
+
+#[test]
+fn rejects_unauthorized_cranker() {
+    let t = RecordSpendFixture::new(u64::MAX);
+
+    let mut accounts = t.accounts(system_account(0));
+    accounts[1].1 = config_account(&t.mollusk, &t.program_id, &t.authority, &[]);
+
+    t.mollusk.process_and_validate_instruction(
+        &t.record(42),
+        &accounts,
+        &[Check::err(ProgramError::from(
+            MeltError::UnauthorizedCranker,
+        ))],
+    );
+}
+
+#[test]
+fn rejects_wrong_tracker_pda() {
+    let t = RecordSpendFixture::new(u64::MAX);
+    let impostor = Pubkey::new_unique();
+
+    let mut accounts = t.accounts(system_account(0));
+    accounts[2].0 = impostor;
+
+    t.mollusk.process_and_validate_instruction(
+        &record_spend(&t.program_id, &t.cranker, &t.config, &impostor, 42),
+        &accounts,
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn rejects_tracker_of_another_epoch() {
+    let t = RecordSpendFixture::new(u64::MAX);
+    let epoch = t.mollusk.sysvars.clock.epoch + 1;
+    let tracker = tracker_address(&t.program_id, &t.config, &t.cranker, epoch);
+
+    let mut accounts = t.accounts(system_account(0));
+    accounts[2].0 = tracker;
+
+    t.mollusk.process_and_validate_instruction(
+        &record_spend(&t.program_id, &t.cranker, &t.config, &tracker, 42),
+        &accounts,
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+fn budget_exceeded() -> Check<'static> {
+    Check::err(ProgramError::from(MeltError::BudgetExceeded))
+}
+
+#[test]
+fn spends_up_to_cap() {
+    let t = RecordSpendFixture::new(1_000);
+    assert_eq!(t.spent_after(system_account(0), 1_000), 1_000);
+}
+
+#[test]
+fn rejects_spend_over_cap() {
+    let t = RecordSpendFixture::new(1_000);
+
+    t.mollusk.process_and_validate_instruction(
+        &t.record(1_001),
+        &t.accounts(system_account(0)),
+        &[budget_exceeded()],
+    );
+}
+
+#[test]
+fn rejects_spend_pushing_existing_tracker_over_cap() {
+    let t = RecordSpendFixture::new(1_000);
+    assert_eq!(t.spent_after(t.tracker_account(900), 100), 1_000);
+
+    t.mollusk.process_and_validate_instruction(
+        &t.record(101),
+        &t.accounts(t.tracker_account(900)),
+        &[budget_exceeded()],
+    );
+}
+
+#[test]
+fn zero_cap_rejects_any_spend() {
+    let t = RecordSpendFixture::new(0);
+
+    t.mollusk.process_and_validate_instruction(
+        &t.record(1),
+        &t.accounts(system_account(0)),
+        &[budget_exceeded()],
+    );
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use common::*;
+use mollusk_svm::result::Check;
+use solana_account::Account;
+use solana_instruction::AccountMeta;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+#[test]
+fn rejects_missing_signer() {
+    let t = DepositFixture::new();
+    let mut ix = deposit(&t.program_id, &t.owner, &t.vault, t.amount);
+    ix.accounts[0] = AccountMeta::new(t.owner, false);
+
+    t.run(
+        &ix,
+        &t.accounts(),
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn rejects_read_only_vault() {
+    let t = DepositFixture::new();
+    let mut ix = deposit(&t.program_id, &t.owner, &t.vault, t.amount);
+    ix.accounts[1] = AccountMeta::new_readonly(t.vault, false);
+
+    t.run(&ix, &t.accounts(), &[Check::err(ProgramError::Immutable)]);
+}
+
+#[test]
+fn rejects_vault_with_wrong_owner() {
+    let t = DepositFixture::new();
+    let ix = deposit(&t.program_id, &t.owner, &t.vault, t.amount);
+
+    let mut accounts = t.accounts();
+    accounts[1].1 = Account::new(0, 0, &t.program_id);
+
+    t.run(
+        &ix,
+        &accounts,
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn rejects_executable_owner() {
+    let t = DepositFixture::new();
+    let ix = deposit(&t.program_id, &t.owner, &t.vault, t.amount);
+
+    let mut accounts = t.accounts();
+    accounts[0].1.executable = true;
+
+    t.run(
+        &ix,
+        &accounts,
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn rejects_duplicate_accounts() {
+    let t = DepositFixture::new();
+    let ix = deposit(&t.program_id, &t.owner, &t.owner, t.amount);
+
+    t.run(
+        &ix,
+        &[(t.owner, system_account(OWNER_LAMPORTS)), system_program()],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn rejects_wrong_pda() {
+    let t = DepositFixture::new();
+    let impostor = Pubkey::new_unique();
+    let ix = deposit(&t.program_id, &t.owner, &impostor, t.amount);
+
+    t.run(
+        &ix,
+        &[
+            (t.owner, system_account(OWNER_LAMPORTS)),
+            (impostor, system_account(0)),
+            system_program(),
+        ],
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn rejects_wrong_system_program() {
+    let t = DepositFixture::new();
+    let fake = Pubkey::new_unique();
+    let mut ix = deposit(&t.program_id, &t.owner, &t.vault, t.amount);
+    ix.accounts[2] = AccountMeta::new_readonly(fake, false);
+
+    let mut accounts = t.accounts();
+    accounts[2] = (fake, Account::new(0, 0, &Pubkey::new_unique()));
+
+    t.run(
+        &ix,
+        &accounts,
+        &[Check::err(ProgramError::IncorrectProgramId)],
+    );
+}
+
+#[test]
+fn rejects_deposit_below_rent() {
+    let t = DepositFixture::new();
+    let ix = deposit(&t.program_id, &t.owner, &t.vault, t.amount - 1);
+
+    t.run(
+        &ix,
+        &t.accounts(),
+        &[Check::err(ProgramError::AccountNotRentExempt)],
+    );
+}
+
+#[test]
+fn rejects_missing_accounts() {
+    let t = DepositFixture::new();
+    let mut ix = deposit(&t.program_id, &t.owner, &t.vault, t.amount);
+    ix.accounts.truncate(2);
+
+    t.run(
+        &ix,
+        &t.accounts()[..2],
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+}
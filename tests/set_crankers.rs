@@ -0,0 +1,191 @@
+mod common;
+
+use common::*;
+use melt::{
+    errors::MeltError,
+    state::{Config, MAX_CRANKERS},
+};
+use mollusk_svm::{result::Check, Mollusk};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+struct SetCrankersFixture {
+    mollusk: Mollusk,
+    program_id: Pubkey,
+    authority: Pubkey,
+    config: Pubkey,
+}
+
+impl SetCrankersFixture {
+    fn new() -> Self {
+        let (mollusk, program_id) = setup();
+        let authority = Pubkey::new_unique();
+        let config = config_address(&program_id, &authority);
+
+        Self {
+            mollusk,
+            program_id,
+            authority,
+            config,
+        }
+    }
+
+    /// The authority and a config that already allows one cranker.
+    fn accounts(&self) -> Vec<(Pubkey, Account)> {
+        vec![
+            (self.authority, system_account(0)),
+            (
+                self.config,
+                config_account(
+                    &self.mollusk,
+                    &self.program_id,
+                    &self.authority,
+                    &[(Pubkey::new_unique(), 1_000)],
+                ),
+            ),
+        ]
+    }
+
+    fn run(&self, crankers: &[(Pubkey, u64)], check: Check) {
+        self.mollusk.process_and_validate_instruction(
+            &set_crankers(&self.program_id, &self.authority, &self.config, crankers),
+            &self.accounts(),
+            &[check],
+        );
+    }
+}
+
+fn crankers(n: usize) -> Vec<(Pubkey, u64)> {
+    (0..n).map(|i| (Pubkey::new_unique(), i as u64)).collect()
+}
+
+#[test]
+fn replaces_crankers() {
+    let t = SetCrankersFixture::new();
+    let expected = crankers(2);
+
+    let result = t.mollusk.process_and_validate_instruction(
+        &set_crankers(&t.program_id, &t.authority, &t.config, &expected),
+        &t.accounts(),
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&t.config).unwrap();
+    let config = Config::load(&account.data).unwrap();
+    let addresses: Vec<Pubkey> = config.crankers().iter().map(|c| c.address).collect();
+    assert_eq!(
+        addresses,
+        expected.iter().map(|(a, _)| *a).collect::<Vec<_>>()
+    );
+}
+
+/// Entries are a 32-byte address followed by a little-endian `u64` cap.
+#[test]
+fn reads_address_and_cap_entries() {
+    let t = SetCrankersFixture::new();
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+
+    let mut ix = set_crankers(&t.program_id, &t.authority, &t.config, &[]);
+    ix.data.extend_from_slice(first.as_ref());
+    ix.data.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+    ix.data.extend_from_slice(second.as_ref());
+    ix.data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0x80]);
+
+    let result =
+        t.mollusk
+            .process_and_validate_instruction(&ix, &t.accounts(), &[Check::success()]);
+
+    let account = result.get_account(&t.config).unwrap();
+    let crankers = Config::load(&account.data).unwrap().crankers();
+    assert_eq!(crankers.len(), 2);
+    assert_eq!(crankers[0].address, first);
+    assert_eq!(crankers[0].epoch_cap(), 1);
+    assert_eq!(crankers[1].address, second);
+    assert_eq!(crankers[1].epoch_cap(), 1 << 63);
+}
+
+#[test]
+fn clears_crankers() {
+    let t = SetCrankersFixture::new();
+
+    let result = t.mollusk.process_and_validate_instruction(
+        &set_crankers(&t.program_id, &t.authority, &t.config, &[]),
+        &t.accounts(),
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&t.config).unwrap();
+    assert!(Config::load(&account.data).unwrap().crankers().is_empty());
+}
+
+#[test]
+fn accepts_max_crankers() {
+    let t = SetCrankersFixture::new();
+    t.run(&crankers(MAX_CRANKERS), Check::success());
+}
+
+#[test]
+fn rejects_too_many_crankers() {
+    let t = SetCrankersFixture::new();
+    t.run(
+        &crankers(MAX_CRANKERS + 1),
+        Check::err(ProgramError::InvalidInstructionData),
+    );
+}
+
+#[test]
+fn rejects_duplicate_crankers() {
+    let t = SetCrankersFixture::new();
+    let cranker = Pubkey::new_unique();
+
+    t.run(
+        &[(cranker, 1), (cranker, 2)],
+        Check::err(ProgramError::InvalidInstructionData),
+    );
+}
+
+#[test]
+fn rejects_partial_entry() {
+    let t = SetCrankersFixture::new();
+    let mut ix = set_crankers(&t.program_id, &t.authority, &t.config, &crankers(1));
+    ix.data.pop();
+
+    t.mollusk.process_and_validate_instruction(
+        &ix,
+        &t.accounts(),
+        &[Check::err(ProgramError::InvalidInstructionData)],
+    );
+}
+
+#[test]
+fn rejects_wrong_authority() {
+    let t = SetCrankersFixture::new();
+    let impostor = Pubkey::new_unique();
+
+    let mut accounts = t.accounts();
+    accounts[0].0 = impostor;
+
+    t.mollusk.process_and_validate_instruction(
+        &set_crankers(&t.program_id, &impostor, &t.config, &crankers(1)),
+        &accounts,
+        &[Check::err(ProgramError::from(MeltError::InvalidAuthority))],
+    );
+}
+
+#[test]
+fn rejects_config_needing_migration() {
+    let t = SetCrankersFixture::new();
+
+    let mut accounts = t.accounts();
+    accounts[1].1.data[1] = Config::VERSION - 1;
+
+    t.mollusk.process_and_validate_instruction(
+        &set_crankers(&t.program_id, &t.authority, &t.config, &crankers(1)),
+        &accounts,
+        &[Check::err(ProgramError::from(
+            MeltError::AccountNeedsMigration,
+        ))],
+    );
+}
@@ -0,0 +1,227 @@
+mod common;
+
+use common::*;
+use melt::{
+    errors::MeltError,
+    instructions::{SPEND_RETENTION_EPOCHS, TEND_TIP_LAMPORTS},
+    state::SpendTracker,
+};
+use mollusk_svm::{result::Check, Mollusk};
+use solana_account::Account;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+const VAULT_LAMPORTS: u64 = 1_000_000_000;
+
+const TENDER_LAMPORTS: u64 = 1_000_000_000;
+
+const CURRENT_EPOCH: u64 = 100;
+
+/// The oldest epoch whose trackers are still retained.
+const RETAINED_EPOCH: u64 = CURRENT_EPOCH - SPEND_RETENTION_EPOCHS;
+
+struct TendFixture {
+    mollusk: Mollusk,
+    program_id: Pubkey,
+    tender: Pubkey,
+    config: Pubkey,
+    vault: Pubkey,
+    trackers: Vec<Pubkey>,
+    accounts: Vec<(Pubkey, Account)>,
+}
+
+impl TendFixture {
+    /// A config and its vault holding `vault_lamports`, with no trackers.
+    fn new(vault_lamports: u64) -> Self {
+        let (mut mollusk, program_id) = setup();
+        mollusk.sysvars.clock.epoch = CURRENT_EPOCH;
+
+        let authority = Pubkey::new_unique();
+        let tender = Pubkey::new_unique();
+        let config = config_address(&program_id, &authority);
+        let vault = vault_address(&program_id, &authority);
+
+        let accounts = vec![
+            (tender, system_account(TENDER_LAMPORTS)),
+            (
+                config,
+                config_account(&mollusk, &program_id, &authority, &[]),
+            ),
+            (vault, system_account(vault_lamports)),
+            system_program(),
+        ];
+
+        Self {
+            mollusk,
+            program_id,
+            tender,
+            config,
+            vault,
+            trackers: Vec::new(),
+            accounts,
+        }
+    }
+
+    /// Adds a tracker from `epoch` recorded against `config`.
+    fn add_tracker_for(&mut self, config: Pubkey, epoch: u64) -> &mut Account {
+        let cranker = Pubkey::new_unique();
+        let tracker = tracker_address(&self.program_id, &config, &cranker, epoch);
+        let account = tracker_account(&self.mollusk, &self.program_id, &config, &cranker, epoch, 0);
+
+        self.trackers.push(tracker);
+        self.accounts.push((tracker, account));
+        &mut self.accounts.last_mut().unwrap().1
+    }
+
+    fn add_tracker(&mut self, epoch: u64) -> &mut Account {
+        self.add_tracker_for(self.config, epoch)
+    }
+
+    fn tracker_rent(&self) -> u64 {
+        self.mollusk.sysvars.rent.minimum_balance(SpendTracker::LEN)
+    }
+
+    fn vault_rent_floor(&self) -> u64 {
+        self.mollusk.sysvars.rent.minimum_balance(0)
+    }
+
+    fn run(&self, trackers: &[Pubkey], checks: &[Check]) {
+        self.mollusk.process_and_validate_instruction(
+            &tend(
+                &self.program_id,
+                &self.tender,
+                &self.config,
+                &self.vault,
+                trackers,
+            ),
+            &self.accounts,
+            checks,
+        );
+    }
+
+    /// Tends every added tracker, expecting them closed, `tip` paid to the
+    /// tender, and the vault left with `vault_lamports`.
+    fn assert_tends(&self, tip: u64, vault_lamports: u64) {
+        let mut checks = vec![
+            Check::success(),
+            Check::account(&self.tender)
+                .lamports(TENDER_LAMPORTS + tip)
+                .build(),
+            Check::account(&self.vault).lamports(vault_lamports).build(),
+        ];
+        checks.extend(
+            self.trackers
+                .iter()
+                .map(|t| Check::account(t).closed().build()),
+        );
+
+        self.run(&self.trackers, &checks);
+    }
+}
+
+#[test]
+fn closes_expired_trackers_and_tips() {
+    let mut t = TendFixture::new(VAULT_LAMPORTS);
+    t.add_tracker(0);
+    t.add_tracker(0);
+
+    let tip = 2 * TEND_TIP_LAMPORTS;
+    t.assert_tends(tip, VAULT_LAMPORTS + 2 * t.tracker_rent() - tip);
+}
+
+#[test]
+fn closes_tracker_just_past_retention() {
+    let mut t = TendFixture::new(VAULT_LAMPORTS);
+    t.add_tracker(RETAINED_EPOCH - 1);
+
+    t.assert_tends(
+        TEND_TIP_LAMPORTS,
+        VAULT_LAMPORTS + t.tracker_rent() - TEND_TIP_LAMPORTS,
+    );
+}
+
+#[test]
+fn rejects_tracker_within_retention() {
+    for epoch in [RETAINED_EPOCH, CURRENT_EPOCH] {
+        let mut t = TendFixture::new(VAULT_LAMPORTS);
+        t.add_tracker(0);
+        t.add_tracker(epoch);
+
+        t.run(
+            &t.trackers,
+            &[Check::err(ProgramError::from(MeltError::AccountNotExpired))],
+        );
+    }
+}
+
+#[test]
+fn rejects_tracker_of_another_config() {
+    let mut t = TendFixture::new(VAULT_LAMPORTS);
+    t.add_tracker_for(Pubkey::new_unique(), 0);
+
+    t.run(&t.trackers, &[Check::err(ProgramError::InvalidAccountData)]);
+}
+
+#[test]
+fn rejects_empty_tracker_list() {
+    let t = TendFixture::new(VAULT_LAMPORTS);
+
+    t.run(
+        &[],
+        &[Check::err(ProgramError::from(MeltError::NothingToTend))],
+    );
+}
+
+#[test]
+fn tips_from_empty_vault_out_of_reclaimed_rent() {
+    let mut t = TendFixture::new(0);
+    t.add_tracker(0);
+
+    let vault_lamports = t.tracker_rent() - TEND_TIP_LAMPORTS;
+    assert!(vault_lamports >= t.vault_rent_floor());
+
+    t.assert_tends(TEND_TIP_LAMPORTS, vault_lamports);
+}
+
+#[test]
+fn caps_tip_at_vault_rent_floor() {
+    let mut t = TendFixture::new(0);
+    let floor = t.vault_rent_floor();
+    let spare = TEND_TIP_LAMPORTS / 2;
+
+    // The vault sits at its floor, and the tracker returns less than a
+    // full tip, so only that much can be paid out.
+    t.accounts[2].1.lamports = floor;
+    t.add_tracker(0).lamports = spare;
+
+    t.assert_tends(spare, floor);
+}
+
+#[test]
+fn skips_tip_when_vault_has_nothing_to_spare() {
+    let mut t = TendFixture::new(0);
+    let floor = t.vault_rent_floor();
+
+    t.accounts[2].1.lamports = floor - 1;
+    t.add_tracker(0).lamports = 1;
+
+    t.assert_tends(0, floor);
+}
+
+#[test]
+fn rejects_duplicate_trackers() {
+    let mut t = TendFixture::new(VAULT_LAMPORTS);
+    t.add_tracker(0);
+
+    t.run(
+        &[t.trackers[0], t.trackers[0]],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn rejects_tracker_aliasing_fixed_account() {
+    let t = TendFixture::new(VAULT_LAMPORTS);
+
+    t.run(&[t.config], &[Check::err(ProgramError::InvalidArgument)]);
+}